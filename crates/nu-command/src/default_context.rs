@@ -0,0 +1,38 @@
+use nu_protocol::engine::{EngineState, StateWorkingSet};
+
+use crate::*;
+
+pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
+    let delta = {
+        let mut working_set = StateWorkingSet::new(&engine_state);
+
+        macro_rules! bind_command {
+            ( $( $command:expr ),* $(,)? ) => {
+                $( working_set.add_decl(Box::new($command)); )*
+            };
+        }
+
+        // Formats
+        bind_command! {
+            ToUrl,
+        };
+
+        // Network
+        bind_command! {
+            Url,
+            UrlBuild,
+            UrlDecode,
+            UrlEncode,
+            UrlJoin,
+            UrlParse,
+        };
+
+        working_set.render()
+    };
+
+    if let Err(err) = engine_state.merge_delta(delta) {
+        eprintln!("Error creating default context: {err:?}");
+    }
+
+    engine_state
+}
@@ -0,0 +1,3 @@
+mod to;
+
+pub use to::*;
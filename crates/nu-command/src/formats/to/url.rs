@@ -1,3 +1,4 @@
+use crate::network::url::query::query_string_from_record;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -37,6 +38,16 @@ impl Command for ToUrl {
                 example: r#"[[foo bar]; ["1" "2"]] | to url"#,
                 result: Some(Value::test_string("foo=1&bar=2")),
             },
+            Example {
+                description: "A list under a key is emitted as repeated pairs",
+                example: r#"{ a: [1 2] } | to url"#,
+                result: Some(Value::test_string("a=1&a=2")),
+            },
+            Example {
+                description: "A nested record is emitted with bracketed keys",
+                example: r#"{ a: { b: 1 } } | to url"#,
+                result: Some(Value::test_string("a[b]=1")),
+            },
         ]
     }
 
@@ -59,35 +70,8 @@ fn to_url(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
             Value::Record {
                 ref cols,
                 ref vals,
-                span,
-            } => {
-                let mut row_vec = vec![];
-                for (k, v) in cols.iter().zip(vals.iter()) {
-                    match v.as_string() {
-                        Ok(s) => {
-                            row_vec.push((k.clone(), s.to_string()));
-                        }
-                        _ => {
-                            return Err(ShellError::UnsupportedInput(
-                                "Expected a record with string values".to_string(),
-                                "value originates from here".into(),
-                                head,
-                                span,
-                            ));
-                        }
-                    }
-                }
-
-                match serde_urlencoded::to_string(row_vec) {
-                    Ok(s) => Ok(s),
-                    _ => Err(ShellError::CantConvert(
-                        "URL".into(),
-                        value.get_type().to_string(),
-                        head,
-                        None,
-                    )),
-                }
-            }
+                ..
+            } => query_string_from_record(cols, vals, head),
             // Propagate existing errors
             Value::Error { error } => Err(error),
             other => Err(ShellError::UnsupportedInput(
@@ -0,0 +1,7 @@
+mod default_context;
+mod formats;
+mod network;
+
+pub use default_context::*;
+pub use formats::*;
+pub use network::*;
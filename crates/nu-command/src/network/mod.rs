@@ -0,0 +1,3 @@
+mod url;
+
+pub use url::*;
@@ -0,0 +1,128 @@
+use super::query::query_string_from_record;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "url build"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url build")
+            .input_output_types(vec![(Type::Record(vec![]), Type::String)])
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts a record of url components into a url."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Outputs a url representing the contents of this record",
+                example: r#"{ scheme: "http", host: "localhost", port: "8080", path: "/api", params: { q: "1" } } | url build"#,
+                result: Some(Value::test_string("http://localhost:8080/api?q=1")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let value = input.into_value(head);
+        let span = value.span()?;
+
+        match value {
+            Value::Record { cols, vals, .. } => {
+                let url = build_url(&cols, &vals, head)?;
+                Ok(Value::string(url, head).into_pipeline_data())
+            }
+            _ => Err(ShellError::UnsupportedInput(
+                "Expected a record from pipeline".to_string(),
+                "value originates from here".into(),
+                head,
+                span,
+            )),
+        }
+    }
+}
+
+fn build_url(cols: &[String], vals: &[Value], head: Span) -> Result<String, ShellError> {
+    let get = |name: &str| {
+        cols.iter()
+            .position(|c| c == name)
+            .map(|idx| &vals[idx])
+    };
+
+    let scheme = get("scheme").map(|v| v.as_string()).transpose()?;
+    let host = get("host").map(|v| v.as_string()).transpose()?;
+    let port = get("port").map(|v| v.as_string()).transpose()?;
+    let path = get("path").map(|v| v.as_string()).transpose()?;
+    let fragment = get("fragment").map(|v| v.as_string()).transpose()?;
+
+    let query = match get("params") {
+        Some(Value::Record { cols, vals, .. }) => query_string_from_record(cols, vals, head)?,
+        Some(v) => v.as_string()?,
+        None => match get("query") {
+            Some(v) => v.as_string()?,
+            None => String::new(),
+        },
+    };
+
+    let mut url = String::new();
+    if let Some(scheme) = scheme {
+        url.push_str(&scheme);
+        url.push_str("://");
+    }
+    if let Some(host) = host {
+        url.push_str(&host);
+    }
+    if let Some(port) = port {
+        if !port.is_empty() {
+            url.push(':');
+            url.push_str(&port);
+        }
+    }
+    if let Some(path) = path {
+        if !path.is_empty() && !path.starts_with('/') {
+            url.push('/');
+        }
+        url.push_str(&path);
+    }
+    if !query.is_empty() {
+        url.push('?');
+        url.push_str(&query);
+    }
+    if let Some(fragment) = fragment {
+        if !fragment.is_empty() {
+            url.push('#');
+            url.push_str(&fragment);
+        }
+    }
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,104 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use percent_encoding::percent_decode_str;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "url decode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url decode")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::List(Box::new(Type::String)), Type::List(Box::new(Type::String))),
+            ])
+            .switch(
+                "plus",
+                "treat '+' as a space (as in an application/x-www-form-urlencoded query)",
+                Some('p'),
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts a percent-encoded string to a plain string."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Decode a percent-encoded string",
+                example: "'https://example.com/foo%20bar' | url decode",
+                result: Some(Value::test_string("https://example.com/foo bar")),
+            },
+            Example {
+                description: "Decode a query value, treating '+' as a space",
+                example: "'foo+bar' | url decode --plus",
+                result: Some(Value::test_string("foo bar")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let plus = call.has_flag("plus");
+        let value = input.into_value(head);
+        decode_value(value, plus, head).map(|v| v.into_pipeline_data())
+    }
+}
+
+fn decode_value(value: Value, plus: bool, head: Span) -> Result<Value, ShellError> {
+    match value {
+        Value::String { val, span } => Ok(Value::string(decode(&val, plus), span)),
+        Value::List { vals, span } => {
+            let vals = vals
+                .into_iter()
+                .map(|v| decode_value(v, plus, head))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::List { vals, span })
+        }
+        Value::Error { error } => Err(error),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected a string or list of strings".to_string(),
+            "value originates from here".into(),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn decode(input: &str, plus: bool) -> String {
+    let owned;
+    let input = if plus {
+        owned = input.replace('+', " ");
+        owned.as_str()
+    } else {
+        input
+    };
+    percent_decode_str(input).decode_utf8_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,123 @@
+use super::query::UNRESERVED;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+// The "loose" set leaves the sub-delimiters and path/query punctuation alone,
+// escaping only characters that are never safe to carry raw (controls, space,
+// and a handful of delimiters).
+const LOOSE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'`')
+    .add(b'#')
+    .add(b'%')
+    .add(b'{')
+    .add(b'}')
+    .add(b'|')
+    .add(b'\\')
+    .add(b'^');
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "url encode"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url encode")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::List(Box::new(Type::String)), Type::List(Box::new(Type::String))),
+            ])
+            .switch(
+                "all",
+                "encode every character outside the RFC 3986 unreserved set (the strict 'component' set)",
+                Some('a'),
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Converts a string to a percent-encoded string."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Without flags, a conservative set that keeps URL punctuation (`/`, `?`, `&`, `=`) intact is used. With --all, every character outside the RFC 3986 unreserved set is escaped, including those delimiters and spaces (as %20)."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Encode a url with escape characters",
+                example: "'https://example.com/foo bar' | url encode",
+                result: Some(Value::test_string("https://example.com/foo%20bar")),
+            },
+            Example {
+                description: "Strictly encode a single component",
+                example: "'foo/bar?baz' | url encode --all",
+                result: Some(Value::test_string("foo%2Fbar%3Fbaz")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let all = call.has_flag("all");
+        let value = input.into_value(head);
+        encode_value(value, all, head).map(|v| v.into_pipeline_data())
+    }
+}
+
+fn encode_value(value: Value, all: bool, head: Span) -> Result<Value, ShellError> {
+    match value {
+        Value::String { val, span } => Ok(Value::string(encode(&val, all), span)),
+        Value::List { vals, span } => {
+            let vals = vals
+                .into_iter()
+                .map(|v| encode_value(v, all, head))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Value::List { vals, span })
+        }
+        Value::Error { error } => Err(error),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected a string or list of strings".to_string(),
+            "value originates from here".into(),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn encode(input: &str, all: bool) -> String {
+    if all {
+        utf8_percent_encode(input, UNRESERVED).to_string()
+    } else {
+        utf8_percent_encode(input, LOOSE).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,291 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Type,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "url join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("url join")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .required(
+                "base",
+                SyntaxShape::String,
+                "the base url to resolve the reference against",
+            )
+            .category(Category::Network)
+    }
+
+    fn usage(&self) -> &str {
+        "Resolves a relative url reference against a base url per RFC 3986."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Resolve a relative reference against a base url",
+                example: r#"'../g' | url join 'http://a/b/c/d'"#,
+                result: Some(Value::test_string("http://a/b/g")),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let base: String = call.req(engine_state, stack, 0)?;
+        let reference = input.into_value(head).as_string()?;
+
+        let resolved = resolve(&base, &reference);
+        Ok(Value::string(resolved, head).into_pipeline_data())
+    }
+}
+
+// An RFC 3986 URI reference split into its five components (appendix B).
+struct Reference {
+    scheme: Option<String>,
+    authority: Option<String>,
+    path: String,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+fn split(input: &str) -> Reference {
+    let (rest, fragment) = match input.split_once('#') {
+        Some((r, f)) => (r, Some(f.to_string())),
+        None => (input, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((r, q)) => (r, Some(q.to_string())),
+        None => (rest, None),
+    };
+
+    // A scheme is only present when a `:` precedes the first `/`.
+    let (scheme, rest) = match rest.find(':') {
+        Some(idx) if !rest[..idx].contains('/') && idx > 0 => {
+            (Some(rest[..idx].to_string()), &rest[idx + 1..])
+        }
+        _ => (None, rest),
+    };
+
+    let (authority, path) = if let Some(after) = rest.strip_prefix("//") {
+        let end = after
+            .find(|c| c == '/' || c == '?' || c == '#')
+            .unwrap_or(after.len());
+        (Some(after[..end].to_string()), after[end..].to_string())
+    } else {
+        (None, rest.to_string())
+    };
+
+    Reference {
+        scheme,
+        authority,
+        path,
+        query,
+        fragment,
+    }
+}
+
+// RFC 3986 §5.2 transform reference.
+fn resolve(base: &str, reference: &str) -> String {
+    let base = split(base);
+    let reference = split(reference);
+
+    let scheme;
+    let authority;
+    let path;
+    let query;
+
+    if reference.scheme.is_some() {
+        scheme = reference.scheme;
+        authority = reference.authority;
+        path = remove_dot_segments(&reference.path);
+        query = reference.query;
+    } else {
+        scheme = base.scheme;
+        if reference.authority.is_some() {
+            authority = reference.authority;
+            path = remove_dot_segments(&reference.path);
+            query = reference.query;
+        } else {
+            authority = base.authority;
+            if reference.path.is_empty() {
+                path = base.path;
+                query = reference.query.or(base.query);
+            } else {
+                if reference.path.starts_with('/') {
+                    path = remove_dot_segments(&reference.path);
+                } else {
+                    let merged = merge(authority.is_some(), &base.path, &reference.path);
+                    path = remove_dot_segments(&merged);
+                }
+                query = reference.query;
+            }
+        }
+    }
+
+    recompose(scheme, authority, &path, query, reference.fragment)
+}
+
+// RFC 3986 §5.2.3 merge.
+fn merge(base_has_authority: bool, base_path: &str, reference_path: &str) -> String {
+    // When the base has an authority and an empty path, the merged path is
+    // the reference path with a leading `/` (§5.2.3).
+    if base_has_authority && base_path.is_empty() {
+        return format!("/{reference_path}");
+    }
+
+    match base_path.rfind('/') {
+        Some(idx) => format!("{}{}", &base_path[..=idx], reference_path),
+        None => reference_path.to_string(),
+    }
+}
+
+// RFC 3986 §5.2.4 remove dot segments, implemented by walking segments and
+// popping on `..`.
+fn remove_dot_segments(path: &str) -> String {
+    if path.is_empty() {
+        return String::new();
+    }
+
+    let leading = path.starts_with('/');
+    let trailing = path.ends_with('/') || path.ends_with("/.") || path.ends_with("/..");
+
+    let mut out: Vec<&str> = vec![];
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+
+    let mut result = String::new();
+    if leading {
+        result.push('/');
+    }
+    result.push_str(&out.join("/"));
+    if trailing && !out.is_empty() {
+        result.push('/');
+    }
+    result
+}
+
+fn recompose(
+    scheme: Option<String>,
+    authority: Option<String>,
+    path: &str,
+    query: Option<String>,
+    fragment: Option<String>,
+) -> String {
+    let mut result = String::new();
+    if let Some(scheme) = scheme {
+        result.push_str(&scheme);
+        result.push(':');
+    }
+    if let Some(authority) = authority {
+        result.push_str("//");
+        result.push_str(&authority);
+    }
+    result.push_str(path);
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(&query);
+    }
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(&fragment);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+
+    #[test]
+    fn resolves_against_empty_base_path() {
+        assert_eq!(resolve("http://a", "g"), "http://a/g");
+    }
+
+    // RFC 3986 §5.4.1 normal examples against the appendix's base
+    // `http://a/b/c/d;p?q`.
+    #[test]
+    fn resolves_rfc3986_normal_examples() {
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            (";x", "http://a/b/c/;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+        ];
+
+        for (reference, expected) in cases {
+            assert_eq!(resolve(base, reference), expected, "reference: {reference}");
+        }
+    }
+
+    // RFC 3986 §5.4.2 abnormal examples, which exercise the merge and
+    // dot-segment-removal branches past the point a normal reference would go.
+    #[test]
+    fn resolves_rfc3986_abnormal_examples() {
+        let base = "http://a/b/c/d;p?q";
+        let cases = [
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+        ];
+
+        for (reference, expected) in cases {
+            assert_eq!(resolve(base, reference), expected, "reference: {reference}");
+        }
+    }
+}
@@ -0,0 +1,14 @@
+mod build;
+mod decode;
+mod encode;
+mod join;
+mod parse;
+pub mod query;
+mod url_;
+
+pub use build::SubCommand as UrlBuild;
+pub use decode::SubCommand as UrlDecode;
+pub use encode::SubCommand as UrlEncode;
+pub use join::SubCommand as UrlJoin;
+pub use parse::SubCommand as UrlParse;
+pub use url_::Url;
@@ -0,0 +1,134 @@
+use nu_protocol::{ShellError, Span, Value};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+// The RFC 3986 "unreserved" set is left untouched; everything else is escaped.
+pub const UNRESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+// Keys additionally keep the brackets used for nested records readable
+// (`parent[child]`), matching the PHP/Rails convention.
+pub const KEY_SET: &AsciiSet = &UNRESERVED.remove(b'[').remove(b']');
+
+// Parse a raw query string (without the leading `?`) into a record the same
+// way `from url` would: split on `&`/`=`, percent-decode both sides, and
+// collapse repeated keys into a list value.
+pub fn record_from_query_string(query: &str, span: Span) -> Value {
+    let mut cols: Vec<String> = vec![];
+    let mut vals: Vec<Value> = vec![];
+
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (raw_key, raw_val) = match pair.split_once('=') {
+            Some((k, v)) => (k, v),
+            None => (pair, ""),
+        };
+
+        let key = percent_decode(raw_key);
+        let val = Value::string(percent_decode(raw_val), span);
+
+        match cols.iter().position(|c| c == &key) {
+            Some(idx) => match &mut vals[idx] {
+                Value::List { vals, .. } => vals.push(val),
+                existing => {
+                    let previous = existing.clone();
+                    *existing = Value::List {
+                        vals: vec![previous, val],
+                        span,
+                    };
+                }
+            },
+            None => {
+                cols.push(key);
+                vals.push(val);
+            }
+        }
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+// Encode a record of components into an `application/x-www-form-urlencoded`
+// query string, supporting repeated pairs for lists and bracketed keys for
+// nested records.
+pub fn query_string_from_record(
+    cols: &[String],
+    vals: &[Value],
+    head: Span,
+) -> Result<String, ShellError> {
+    let mut pairs = vec![];
+    for (k, v) in cols.iter().zip(vals.iter()) {
+        collect_pairs(k.clone(), v, &mut pairs, head)?;
+    }
+    Ok(encode_pairs(&pairs))
+}
+
+// Flatten a value reachable under `key` into a list of `(key, value)` string
+// pairs. Lists emit one pair per element under the same key (an empty list
+// emits nothing); records recurse with bracketed keys.
+fn collect_pairs(
+    key: String,
+    value: &Value,
+    out: &mut Vec<(String, String)>,
+    head: Span,
+) -> Result<(), ShellError> {
+    match value {
+        Value::List { vals, .. } => {
+            for v in vals {
+                collect_pairs(key.clone(), v, out, head)?;
+            }
+            Ok(())
+        }
+        Value::Record { cols, vals, .. } => {
+            for (k, v) in cols.iter().zip(vals.iter()) {
+                collect_pairs(format!("{key}[{k}]"), v, out, head)?;
+            }
+            Ok(())
+        }
+        Value::Error { error } => Err(error.clone()),
+        _ => {
+            out.push((key, scalar_to_string(value, head)?));
+            Ok(())
+        }
+    }
+}
+
+// Stringify a scalar value, accepting the non-string scalars a URL query can
+// meaningfully carry.
+fn scalar_to_string(value: &Value, head: Span) -> Result<String, ShellError> {
+    match value {
+        Value::String { val, .. } => Ok(val.clone()),
+        Value::Int { val, .. } => Ok(val.to_string()),
+        Value::Float { val, .. } => Ok(val.to_string()),
+        Value::Bool { val, .. } => Ok(val.to_string()),
+        Value::Filesize { val, .. } => Ok(val.to_string()),
+        Value::Date { val, .. } => Ok(val.to_rfc3339()),
+        other => Err(ShellError::UnsupportedInput(
+            "Expected a record with scalar, list, or record values".to_string(),
+            "value originates from here".into(),
+            head,
+            other.expect_span(),
+        )),
+    }
+}
+
+fn encode_pairs(pairs: &[(String, String)]) -> String {
+    pairs
+        .iter()
+        .map(|(k, v)| {
+            format!(
+                "{}={}",
+                utf8_percent_encode(k, KEY_SET),
+                utf8_percent_encode(v, UNRESERVED),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+// Percent-decode a component, treating `+` as a literal space.
+pub fn percent_decode(input: &str) -> String {
+    let replaced = input.replace('+', " ");
+    percent_decode_str(&replaced).decode_utf8_lossy().into_owned()
+}